@@ -39,7 +39,7 @@ def build_rust_library():
         return False
     
     # Build the library
-    if not run_command("cargo build --release", cwd=rust_dir):
+    if not run_command("cargo build --release --features extension-module", cwd=rust_dir):
         print("Failed to build Rust library")
         return False
     
@@ -0,0 +1,160 @@
+//! Throughput of the text moderation hot paths
+//!
+//! Run with `cargo bench`; the baselines reimplement the approaches each
+//! optimization replaced, so the speedup can be read off side by side.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use regex::Regex;
+use rust_moderation::{ModerationConfig, TextModerator};
+
+/// `count` distinct made-up words, none of which occur in English text
+fn word_list(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("zq{}x{}", i, i * 7 % 13)).collect()
+}
+
+/// Everyday sentences, with every tenth one containing a listed word
+fn texts(count: usize, words: &[String]) -> Vec<String> {
+    (0..count)
+        .map(|i| {
+            if i % 10 == 0 {
+                format!("Message {} says {} to everyone in the thread", i, words[i % words.len()])
+            } else {
+                format!("Message {} is an ordinary reply about the weekend plans and the weather", i)
+            }
+        })
+        .collect()
+}
+
+fn word_moderator(words: &[String]) -> TextModerator {
+    let mut moderator = TextModerator::empty(ModerationConfig::default());
+    moderator.add_profanity_words(words.to_vec());
+    moderator
+}
+
+/// Compiled word regexes against recompiling one per word for every text, as `check_profanity` once did
+fn cached_word_regexes(c: &mut Criterion) {
+    let words = word_list(2_000);
+    let moderator = word_moderator(&words);
+    let batch = texts(10_000, &words);
+    let batch: Vec<&str> = batch.iter().map(String::as_str).collect();
+    let text = batch[0];
+    
+    let mut group = c.benchmark_group("cached_word_regexes");
+    group.sample_size(10);
+    group.bench_function("cached/one_text", |b| b.iter(|| moderator.moderate_text(black_box(text))));
+    group.bench_function("recompiled/one_text", |b| {
+        b.iter(|| {
+            let lower = black_box(text).to_lowercase();
+            words
+                .iter()
+                .filter(|word| Regex::new(&format!(r"\b{}\b", regex::escape(word))).unwrap().is_match(&lower))
+                .count()
+        })
+    });
+    group.bench_function("cached/batch_10k", |b| b.iter(|| moderator.moderate_batch(black_box(&batch))));
+    group.finish();
+}
+
+/// One automaton pass over a paragraph against trying each word's precompiled regex in turn
+fn word_automaton(c: &mut Criterion) {
+    let words = word_list(5_000);
+    let moderator = word_moderator(&words);
+    let regexes: Vec<Regex> = words
+        .iter()
+        .map(|word| Regex::new(&format!(r"\b{}\b", regex::escape(word))).unwrap())
+        .collect();
+    let paragraph = texts(20, &words).join(". ");
+    
+    let mut group = c.benchmark_group("word_automaton");
+    group.bench_function("automaton/paragraph", |b| b.iter(|| moderator.contains_profanity(black_box(&paragraph))));
+    group.bench_function("regex_per_word/paragraph", |b| {
+        b.iter(|| {
+            let lower = black_box(&paragraph).to_lowercase();
+            regexes.iter().filter(|regex| regex.is_match(&lower)).count()
+        })
+    });
+    group.finish();
+}
+
+/// Early-exit gating against building the full result, for a clean and a clearly bad text
+fn first_flag(c: &mut Criterion) {
+    let moderator = TextModerator::with_config(ModerationConfig::default()).unwrap();
+    let clean = "Thanks for the update, I'll review the draft tomorrow morning and send notes";
+    let bad = "THIS IS SPAM!!! CLICK HERE NOW!!! FREE MONEY FREE MONEY FREE MONEY";
+    
+    let mut group = c.benchmark_group("first_flag");
+    for (name, text) in [("clean", clean), ("bad", bad)] {
+        group.bench_function(format!("is_flagged/{}", name), |b| b.iter(|| moderator.is_flagged(black_box(text))));
+        group.bench_function(format!("first_flag/{}", name), |b| b.iter(|| moderator.first_flag(black_box(text))));
+        group.bench_function(format!("moderate_text/{}", name), |b| b.iter(|| moderator.moderate_text(black_box(text))));
+    }
+    group.finish();
+}
+
+/// Moderating an ASCII-heavy batch, which lowercases into a reused buffer, against the same batch
+/// with one accented letter per text, which takes the Unicode path
+fn ascii_lowercase(c: &mut Criterion) {
+    let words = word_list(500);
+    let moderator = word_moderator(&words);
+    let ascii = texts(1_000, &words);
+    let accented: Vec<String> = ascii.iter().map(|text| format!("{} caf\u{e9}", text)).collect();
+    let ascii: Vec<&str> = ascii.iter().map(String::as_str).collect();
+    let accented: Vec<&str> = accented.iter().map(String::as_str).collect();
+    
+    let mut group = c.benchmark_group("ascii_lowercase");
+    group.bench_function("moderate/ascii", |b| {
+        b.iter(|| {
+            for text in &ascii {
+                black_box(moderator.moderate_text(black_box(text)));
+            }
+        })
+    });
+    group.bench_function("moderate/accented", |b| {
+        b.iter(|| {
+            for text in &accented {
+                black_box(moderator.moderate_text(black_box(text)));
+            }
+        })
+    });
+    // The lowercasing step on its own: a fresh allocation per text against one reused buffer
+    group.bench_function("to_lowercase/ascii", |b| {
+        b.iter(|| ascii.iter().map(|text| black_box(text).to_lowercase().len()).sum::<usize>())
+    });
+    group.bench_function("reused_buffer/ascii", |b| {
+        let mut buffer = String::new();
+        b.iter(|| {
+            ascii
+                .iter()
+                .map(|text| {
+                    buffer.clear();
+                    buffer.push_str(black_box(text));
+                    buffer.make_ascii_lowercase();
+                    buffer.len()
+                })
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+/// A category's patterns checked in one `RegexSet` pass against one `is_match` scan per pattern,
+/// on a long input
+fn pattern_sets(c: &mut Criterion) {
+    let patterns: Vec<String> = (0..60).map(|i| format!(r"\bpromo{}\s+code\s+\d{{4}}\b", i)).collect();
+    let mut moderator = TextModerator::empty(ModerationConfig::default());
+    moderator.add_pattern_category("promo".to_string(), patterns.clone(), 0.5).unwrap();
+    let regexes: Vec<Regex> = patterns.iter().map(|pattern| Regex::new(pattern).unwrap()).collect();
+    let set = regex::RegexSet::new(&patterns).unwrap();
+    let long_input = format!("{} promo42 code 1234", texts(200, &word_list(10)).join(" ")).to_lowercase();
+    
+    let mut group = c.benchmark_group("pattern_sets");
+    group.bench_function("regex_set/long_input", |b| b.iter(|| set.matches(black_box(&long_input)).iter().count()));
+    group.bench_function("regex_loop/long_input", |b| {
+        b.iter(|| regexes.iter().filter(|regex| regex.is_match(black_box(&long_input))).count())
+    });
+    group.bench_function("moderate_text/long_input", |b| b.iter(|| moderator.moderate_text(black_box(&long_input))));
+    group.finish();
+}
+
+criterion_group!(benches, cached_word_regexes, word_automaton, first_flag, ascii_lowercase, pattern_sets);
+criterion_main!(benches);
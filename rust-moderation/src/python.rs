@@ -0,0 +1,1100 @@
+//! Python bindings over the pyo3-free types in `core`
+
+use crate::core::{
+    AuthorTracker, ImageInfo, ImageModerationResult, ImageModerator, ImageValidation, LengthPolicy, MatchMode,
+    ModerationConfig, ModerationError, ModerationResult, Severity, StreamingModerator, TextDeduper,
+    TextModerator, TextModeratorBuilder, RESULT_SCHEMA_VERSION,
+};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator, PyList};
+use std::collections::{HashMap, VecDeque};
+
+#[pymethods]
+impl ModerationResult {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+    
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        self.to_dict_versioned(py, RESULT_SCHEMA_VERSION)
+    }
+    
+    /// Dict in the shape of an older schema version, so pinned clients keep working as fields are added
+    fn to_dict_versioned(&self, py: Python, version: u32) -> PyResult<PyObject> {
+        let fields = Self::schema_fields(version).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported schema version {}; expected 1 to {}",
+                version, RESULT_SCHEMA_VERSION
+            ))
+        })?;
+        
+        let dict = PyDict::new(py);
+        for field in fields {
+            match field {
+                "is_appropriate" => dict.set_item(field, self.is_appropriate)?,
+                "confidence_score" => dict.set_item(field, self.confidence_score)?,
+                "flagged_categories" => dict.set_item(field, &self.flagged_categories)?,
+                "processed_text" => dict.set_item(field, &self.processed_text)?,
+                "schema_version" => dict.set_item(field, version)?,
+                "category_scores" => dict.set_item(field, &self.category_scores)?,
+                "max_severity" => dict.set_item(field, self.max_severity.map(|s| s.as_str()))?,
+                "detected_language" => dict.set_item(field, &self.detected_language)?,
+                "near_misses" => dict.set_item(field, &self.near_misses)?,
+                "mentions" => dict.set_item(field, &self.mentions)?,
+                "hashtags" => dict.set_item(field, &self.hashtags)?,
+                "normalization_applied" => dict.set_item(field, &self.normalization_applied)?,
+                "matches" => {
+                    let matches = PyList::empty(py);
+                    for (category, start, end, matched) in &self.matches {
+                        let entry = PyDict::new(py);
+                        entry.set_item("category", category)?;
+                        entry.set_item("start", start)?;
+                        entry.set_item("end", end)?;
+                        entry.set_item("text", matched)?;
+                        matches.append(entry)?;
+                    }
+                    dict.set_item(field, matches)?;
+                }
+                "incomplete" => dict.set_item(field, self.incomplete)?,
+                "truncated" => dict.set_item(field, self.truncated)?,
+                "profanity_tier" => dict.set_item(field, &self.profanity_tier)?,
+                "original_text" => dict.set_item(field, &self.original_text)?,
+                _ => unreachable!("schema field {} has no Python value", field),
+            }
+        }
+        Ok(dict.into())
+    }
+    
+    /// Write the flagged results to a `"csv"` or `"ndjson"` file for review, returning how many were written
+    #[staticmethod]
+    #[pyo3(name = "export_flagged")]
+    fn py_export_flagged(results: Vec<ModerationResult>, path: &str, format: &str) -> PyResult<usize> {
+        ModerationResult::export_flagged(&results, path, format).map_err(|e| e.into_pyerr("Failed to export results"))
+    }
+    
+    /// Serialize to a JSON string, e.g. for audit logs
+    #[pyo3(name = "to_json")]
+    fn py_to_json(&self) -> PyResult<String> {
+        self.to_json().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to serialize result: {}", e))
+        })
+    }
+    
+    /// Rebuild a result from the output of `to_json`
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(json: &str) -> PyResult<Self> {
+        Self::from_json(json).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid result JSON: {}", e))
+        })
+    }
+}
+
+#[pymethods]
+impl ImageModerationResult {
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("is_appropriate", self.is_appropriate)?;
+        dict.set_item("flagged_categories", &self.flagged_categories)?;
+        dict.set_item("confidence_score", self.confidence_score)?;
+        dict.set_item("width", self.width)?;
+        dict.set_item("height", self.height)?;
+        dict.set_item("format", &self.format)?;
+        dict.set_item("file_size", self.file_size)?;
+        Ok(dict.into())
+    }
+    
+    /// Serialize to a JSON string, e.g. for audit logs
+    #[pyo3(name = "to_json")]
+    fn py_to_json(&self) -> PyResult<String> {
+        self.to_json().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to serialize result: {}", e))
+        })
+    }
+}
+
+#[pymethods]
+impl ModerationConfig {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+    
+    /// Override the severity reported for a category
+    #[pyo3(name = "set_severity")]
+    fn py_set_severity(&mut self, category: &str, severity: Severity) {
+        self.set_severity(category, severity);
+    }
+    
+    /// Put a profanity word in the `mild`, `strong` or `slur` tier
+    #[pyo3(name = "set_profanity_tier")]
+    fn py_set_profanity_tier(&mut self, word: &str, tier: &str) {
+        self.set_profanity_tier(word, tier);
+    }
+}
+
+#[pymethods]
+impl TextModerator {
+    #[new]
+    #[pyo3(signature = (normalize_leetspeak = false, normalize_confusables = false))]
+    fn py_new(normalize_leetspeak: bool, normalize_confusables: bool) -> PyResult<Self> {
+        let mut builder = TextModeratorBuilder::new();
+        if normalize_leetspeak {
+            builder = builder.enable_leetspeak();
+        }
+        if normalize_confusables {
+            builder = builder.enable_confusables();
+        }
+        builder.build().map_err(value_error)
+    }
+    
+    /// Create a moderator with custom weights and thresholds
+    #[staticmethod]
+    #[pyo3(name = "with_config")]
+    fn py_with_config(config: ModerationConfig) -> PyResult<Self> {
+        Self::with_config(config).map_err(value_error)
+    }
+    
+    /// Create a moderator with no words, patterns or allowlist; content comes entirely from the caller
+    #[staticmethod]
+    #[pyo3(name = "empty", signature = (config = None))]
+    fn py_empty(config: Option<ModerationConfig>) -> Self {
+        Self::empty(config.unwrap_or_default())
+    }
+    
+    /// Create a moderator whose words and patterns come from a JSON wordlist file
+    #[staticmethod]
+    #[pyo3(name = "from_file", signature = (path, config = None))]
+    fn py_from_file(path: &str, config: Option<ModerationConfig>) -> PyResult<Self> {
+        Self::from_file(path, config.unwrap_or_default()).map_err(|e| wordlist_error(path, e))
+    }
+    
+    /// Moderate a single text string
+    #[pyo3(name = "moderate_text")]
+    fn py_moderate_text(&self, text: &str) -> ModerationResult {
+        self.moderate_text(text)
+    }
+    
+    /// Moderate a text under `overrides` in place of this moderator's config, leaving it unchanged
+    #[pyo3(name = "moderate_text_with")]
+    fn py_moderate_text_with(&self, text: &str, overrides: PyRef<'_, ModerationConfig>) -> ModerationResult {
+        self.moderate_text_with(text, &overrides)
+    }
+    
+    /// Moderate a text, also returning `{"elapsed_us", "patterns_evaluated", "word_set_checks"}`
+    #[pyo3(name = "moderate_text_timed")]
+    fn py_moderate_text_timed(&self, py: Python, text: &str) -> PyResult<(ModerationResult, PyObject)> {
+        let (result, metrics) = self.moderate_text_timed(text);
+        let dict = PyDict::new(py);
+        dict.set_item("elapsed_us", metrics.elapsed_us)?;
+        dict.set_item("patterns_evaluated", metrics.patterns_evaluated)?;
+        dict.set_item("word_set_checks", metrics.word_set_checks)?;
+        Ok((result, dict.into()))
+    }
+    
+    /// Moderate the visible text of an HTML fragment, ignoring tags, attributes, comments and scripts
+    #[pyo3(name = "moderate_html")]
+    fn py_moderate_html(&self, html: &str) -> ModerationResult {
+        self.moderate_html(html)
+    }
+    
+    /// Moderate the string values at dot-separated `fields` of a JSON document; raises ValueError for invalid JSON
+    #[pyo3(name = "moderate_json_fields")]
+    fn py_moderate_json_fields(&self, json: &str, fields: Vec<String>) -> PyResult<ModerationResult> {
+        self.moderate_json_fields(json, fields).map_err(value_error)
+    }
+    
+    /// Moderate multiple texts in parallel
+    #[pyo3(name = "moderate_batch")]
+    fn py_moderate_batch(&self, texts: Vec<&str>) -> Vec<ModerationResult> {
+        self.moderate_batch(&texts)
+    }
+    
+    /// Iterator over the results for any iterable of texts, moderating `chunk_size` at a time in
+    /// parallel, so results arrive before the whole batch is done and memory stays bounded
+    #[pyo3(name = "moderate_batch_iter", signature = (texts, chunk_size = 256))]
+    fn py_moderate_batch_iter(slf: PyRef<'_, Self>, texts: &PyAny, chunk_size: usize) -> PyResult<PyBatchIterator> {
+        Ok(PyBatchIterator {
+            texts: texts.iter()?.into(),
+            moderator: slf.into(),
+            chunk_size: chunk_size.max(1),
+            ready: VecDeque::new(),
+        })
+    }
+    
+    /// Moderate multiple texts in parallel as `(result, None)` or `(None, error message)` per text,
+    /// so one failing text doesn't lose the rest of the batch
+    #[pyo3(name = "moderate_batch_lenient")]
+    fn py_moderate_batch_lenient(&self, texts: Vec<&str>) -> Vec<(Option<ModerationResult>, Option<String>)> {
+        self.moderate_batch_lenient(&texts)
+            .into_iter()
+            .map(|outcome| match outcome {
+                Ok(result) => (Some(result), None),
+                Err(message) => (None, Some(message)),
+            })
+            .collect()
+    }
+    
+    /// Counts per flagged category and severity, fraction appropriate, mean/max confidence and most common category
+    #[pyo3(name = "moderate_batch_summary")]
+    fn py_moderate_batch_summary(&self, py: Python, texts: Vec<&str>) -> PyResult<PyObject> {
+        let summary = self.moderate_batch_summary(&texts);
+        let severity_counts: HashMap<&str, usize> = summary
+            .severity_counts
+            .iter()
+            .map(|(severity, &count)| (severity.as_str(), count))
+            .collect();
+        
+        let dict = PyDict::new(py);
+        dict.set_item("total", summary.total)?;
+        dict.set_item("category_counts", summary.category_counts)?;
+        dict.set_item("severity_counts", severity_counts)?;
+        dict.set_item("appropriate_fraction", summary.appropriate_fraction)?;
+        dict.set_item("mean_confidence", summary.mean_confidence)?;
+        dict.set_item("max_confidence", summary.max_confidence)?;
+        dict.set_item("most_common_category", summary.most_common_category)?;
+        Ok(dict.into())
+    }
+    
+    /// Fit per-category thresholds to (text, is_appropriate) examples and report precision/recall
+    #[pyo3(name = "calibrate")]
+    fn py_calibrate(&mut self, py: Python, labeled: Vec<(String, bool)>) -> PyResult<PyObject> {
+        let report = self.calibrate(labeled);
+        
+        let categories = PyDict::new(py);
+        for (name, (threshold, precision, recall)) in report.categories {
+            let entry = PyDict::new(py);
+            entry.set_item("threshold", threshold)?;
+            entry.set_item("precision", precision)?;
+            entry.set_item("recall", recall)?;
+            categories.set_item(name, entry)?;
+        }
+        
+        let dict = PyDict::new(py);
+        dict.set_item("categories", categories)?;
+        dict.set_item("precision", report.precision)?;
+        dict.set_item("recall", report.recall)?;
+        dict.set_item("f1", report.f1)?;
+        Ok(dict.into())
+    }
+    
+    /// Add custom profanity words
+    #[pyo3(name = "add_profanity_words")]
+    fn py_add_profanity_words(&mut self, words: Vec<String>) {
+        self.add_profanity_words(words);
+    }
+    
+    /// Remove profanity words, returning how many were actually in the list
+    #[pyo3(name = "remove_profanity_words")]
+    fn py_remove_profanity_words(&mut self, words: Vec<String>) -> usize {
+        self.remove_profanity_words(words)
+    }
+    
+    /// Remove every profanity word; obfuscation patterns still apply
+    #[pyo3(name = "clear_profanity_words")]
+    fn py_clear_profanity_words(&mut self) {
+        self.clear_profanity_words();
+    }
+    
+    /// Current profanity words, sorted
+    #[pyo3(name = "get_profanity_words")]
+    fn py_get_profanity_words(&self) -> Vec<String> {
+        self.get_profanity_words()
+    }
+    
+    /// Total number of regex patterns across profanity, threats, spam and custom categories
+    #[pyo3(name = "get_pattern_count")]
+    fn py_get_pattern_count(&self) -> usize {
+        self.get_pattern_count()
+    }
+    
+    /// Summary of word counts, pattern counts and thresholds for debugging and admin UIs
+    #[pyo3(name = "describe_config")]
+    fn py_describe_config(&self, py: Python) -> PyResult<PyObject> {
+        let summary = self.describe_config();
+        
+        let thresholds = PyDict::new(py);
+        thresholds.set_item("min_confidence", summary.min_confidence)?;
+        thresholds.set_item("category_thresholds", summary.category_thresholds)?;
+        thresholds.set_item("min_language_confidence", summary.min_language_confidence)?;
+        
+        let dict = PyDict::new(py);
+        dict.set_item("word_counts", summary.word_counts)?;
+        dict.set_item("language_word_counts", summary.language_word_counts)?;
+        dict.set_item("pattern_counts", summary.pattern_counts)?;
+        dict.set_item("allowlist_size", summary.allowlist_size)?;
+        dict.set_item("thresholds", thresholds)?;
+        dict.set_item("normalize_leetspeak", summary.normalize_leetspeak)?;
+        dict.set_item("normalize_confusables", summary.normalize_confusables)?;
+        dict.set_item("fold_diacritics", summary.fold_diacritics)?;
+        dict.set_item("collapse_repeats", summary.collapse_repeats)?;
+        dict.set_item("phonetic_matching", summary.phonetic_matching)?;
+        dict.set_item("detect_pii", summary.detect_pii)?;
+        Ok(dict.into())
+    }
+    
+    /// Try a pattern on sample texts as `{"sample", "matched", "spans"}` per sample; raises ValueError if it doesn't compile
+    #[staticmethod]
+    #[pyo3(name = "test_pattern")]
+    fn py_test_pattern(py: Python, pattern: &str, samples: Vec<&str>) -> PyResult<Vec<PyObject>> {
+        let spans = Self::test_pattern(pattern, &samples).map_err(value_error)?;
+        samples
+            .iter()
+            .zip(spans)
+            .map(|(sample, spans)| {
+                let dict = PyDict::new(py);
+                dict.set_item("sample", sample)?;
+                dict.set_item("matched", !spans.is_empty())?;
+                dict.set_item("spans", spans)?;
+                Ok(dict.into())
+            })
+            .collect()
+    }
+    
+    /// Register regex patterns that flag `name`, scoring `weight` per matching pattern
+    #[pyo3(name = "add_pattern_category")]
+    fn py_add_pattern_category(&mut self, name: String, patterns: Vec<String>, weight: f64) -> PyResult<()> {
+        self.add_pattern_category(name, patterns, weight).map_err(value_error)
+    }
+    
+    /// Add regex patterns to the threats check; raises ValueError, adding none, if any doesn't compile
+    #[pyo3(name = "add_threat_patterns")]
+    fn py_add_threat_patterns(&mut self, patterns: Vec<String>) -> PyResult<()> {
+        self.add_threat_patterns(patterns).map_err(value_error)
+    }
+    
+    /// Add regex patterns to the spam check; raises ValueError, adding none, if any doesn't compile
+    #[pyo3(name = "add_spam_patterns")]
+    fn py_add_spam_patterns(&mut self, patterns: Vec<String>) -> PyResult<()> {
+        self.add_spam_patterns(patterns).map_err(value_error)
+    }
+    
+    /// Add literal phrases to the threats check
+    #[pyo3(name = "add_threat_phrases")]
+    fn py_add_threat_phrases(&mut self, phrases: Vec<String>) -> PyResult<()> {
+        self.add_threat_phrases(phrases).map_err(value_error)
+    }
+    
+    /// Add literal phrases to the spam check
+    #[pyo3(name = "add_spam_phrases")]
+    fn py_add_spam_phrases(&mut self, phrases: Vec<String>) -> PyResult<()> {
+        self.add_spam_phrases(phrases).map_err(value_error)
+    }
+    
+    /// Add profanity words used instead of the default list when text is detected as `language`
+    #[pyo3(name = "add_language_words")]
+    fn py_add_language_words(&mut self, language: &str, words: Vec<String>) {
+        self.add_language_words(language, words);
+    }
+    
+    /// ISO 639-3 code (e.g. "eng", "fra") of the text's language, or None when detection is too uncertain
+    #[pyo3(name = "detect_language")]
+    fn py_detect_language(&self, text: &str) -> Option<String> {
+        self.detect_language(text)
+    }
+    
+    /// Replace `from` with `to` during normalization, before leetspeak decoding; raises ValueError
+    /// if `from` is empty
+    #[pyo3(name = "add_normalization_rule")]
+    fn py_add_normalization_rule(&mut self, from: &str, to: &str) -> PyResult<()> {
+        self.add_normalization_rule(from, to).map_err(value_error)
+    }
+    
+    /// Add legitimate words that should never be flagged as profanity, even when a pattern matches inside them
+    #[pyo3(name = "add_allowlist_words")]
+    fn py_add_allowlist_words(&mut self, words: Vec<String>) {
+        self.add_allowlist_words(words);
+    }
+    
+    /// Add words to a named category, creating it if needed; matches flag under `name`
+    #[pyo3(name = "load_category")]
+    fn py_load_category(&mut self, name: &str, words: Vec<String>) {
+        self.load_category(name, words);
+    }
+    
+    /// Merge another JSON wordlist file into this moderator
+    #[pyo3(name = "load_file")]
+    fn py_load_file(&mut self, path: &str) -> PyResult<()> {
+        self.load_file(path).map_err(|e| wordlist_error(path, e))
+    }
+    
+    /// Write the words, patterns and config to a binary snapshot that `load_snapshot` restores
+    #[pyo3(name = "save_snapshot")]
+    fn py_save_snapshot(&self, path: &str) -> PyResult<()> {
+        self.save_snapshot(path).map_err(|e| snapshot_error(path, e))
+    }
+    
+    /// Restore a moderator written by `save_snapshot`, rebuilding its matchers
+    #[staticmethod]
+    #[pyo3(name = "load_snapshot")]
+    fn py_load_snapshot(path: &str) -> PyResult<Self> {
+        Self::load_snapshot(path).map_err(|e| snapshot_error(path, e))
+    }
+    
+    /// Check if text contains profanity
+    #[pyo3(name = "contains_profanity")]
+    fn py_contains_profanity(&self, text: &str) -> bool {
+        self.contains_profanity(text)
+    }
+    
+    /// Get profanity score for text
+    #[pyo3(name = "get_profanity_score")]
+    fn py_get_profanity_score(&self, text: &str) -> f64 {
+        self.get_profanity_score(text)
+    }
+    
+    /// Which rule fired in each category, as category -> [{rule, matched_text, score}]
+    #[pyo3(name = "explain")]
+    fn py_explain(&self, py: Python, text: &str) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        for (category, rule, matched_text, score) in self.explain(text) {
+            let entry = PyDict::new(py);
+            entry.set_item("rule", rule)?;
+            entry.set_item("matched_text", matched_text)?;
+            entry.set_item("score", score)?;
+            
+            let rules = match dict.get_item(&category)? {
+                Some(rules) => rules.downcast::<PyList>()?,
+                None => {
+                    let rules = PyList::empty(py);
+                    dict.set_item(&category, rules)?;
+                    rules
+                }
+            };
+            rules.append(entry)?;
+        }
+        Ok(dict.into())
+    }
+    
+    /// How unlike English the text's words are, from 0.0 (prose) to 1.0 (keyboard mash or random strings)
+    #[pyo3(name = "gibberish_score")]
+    fn py_gibberish_score(&self, text: &str) -> f64 {
+        self.gibberish_score(text)
+    }
+    
+    /// Whether any category flags `text`, stopping at the first one
+    #[pyo3(name = "is_flagged")]
+    fn py_is_flagged(&self, text: &str) -> bool {
+        self.is_flagged(text)
+    }
+    
+    /// The first category found to flag `text`, without building a full result
+    #[pyo3(name = "first_flag")]
+    fn py_first_flag(&self, text: &str) -> Option<String> {
+        self.first_flag(text)
+    }
+    
+    /// Single 0-1 toxicity rating for ranking a review queue
+    #[pyo3(name = "get_toxicity_score")]
+    fn py_get_toxicity_score(&self, text: &str) -> f64 {
+        self.get_toxicity_score(text)
+    }
+    
+    /// Each match with up to `context_chars` characters either side, for human review
+    #[pyo3(name = "get_snippets")]
+    fn py_get_snippets(&self, text: &str, context_chars: usize) -> Vec<String> {
+        self.get_snippets(text, context_chars)
+    }
+    
+    /// Replace every profanity match with the mask character, keeping the rest of the text
+    #[pyo3(name = "censor_text", signature = (text, mask = '*'))]
+    fn py_censor_text(&self, text: &str, mask: char) -> String {
+        self.censor_text(text, mask)
+    }
+    
+    /// Links in `text`, with or without a scheme
+    #[pyo3(name = "extract_urls")]
+    fn py_extract_urls(&self, text: &str) -> Vec<String> {
+        self.extract_urls(text)
+    }
+    
+    /// Links to these domains (and their subdomains) are flagged as spam
+    #[pyo3(name = "add_blocked_domains")]
+    fn py_add_blocked_domains(&mut self, domains: Vec<String>) {
+        self.add_blocked_domains(domains);
+    }
+    
+    /// Links to these domains are never flagged, even in strict mode
+    #[pyo3(name = "add_allowed_domains")]
+    fn py_add_allowed_domains(&mut self, domains: Vec<String>) {
+        self.add_allowed_domains(domains);
+    }
+    
+    /// Find emails and phone numbers disguised with words, like `john at gmail dot com`, as (type, (start, end))
+    #[pyo3(name = "detect_contact_sharing")]
+    fn py_detect_contact_sharing(&self, text: &str) -> Vec<(String, (usize, usize))> {
+        self.detect_contact_sharing(text)
+    }
+    
+    /// Find API keys, tokens and private keys as (type, (start, end)) in the text as given
+    #[pyo3(name = "detect_secrets")]
+    fn py_detect_secrets(&self, text: &str) -> Vec<(String, (usize, usize))> {
+        self.detect_secrets(text)
+    }
+    
+    /// Find emails, phone numbers, Luhn-valid card numbers and IPv4 addresses as (type, (start, end))
+    #[pyo3(name = "detect_pii")]
+    fn py_detect_pii(&self, text: &str) -> Vec<(String, (usize, usize))> {
+        self.detect_pii(text)
+    }
+    
+    /// Distinct words mixing letters from more than one script, a spoofing signal
+    #[pyo3(name = "detect_mixed_script")]
+    fn py_detect_mixed_script(&self, text: &str) -> Vec<String> {
+        self.detect_mixed_script(text)
+    }
+    
+    /// Links posing as a protected brand's domain as `(start, end, unicode host)`, offsets into the
+    /// text as given
+    #[pyo3(name = "detect_deceptive_urls")]
+    fn py_detect_deceptive_urls(&self, text: &str) -> Vec<(usize, usize, String)> {
+        self.detect_deceptive_urls(text)
+    }
+    
+    /// Bidi override and other formatting control characters as `(byte offset, "U+202E")`, offsets
+    /// into the text as given
+    #[pyo3(name = "detect_spoofing_chars")]
+    fn py_detect_spoofing_chars(&self, text: &str) -> Vec<(usize, String)> {
+        self.detect_spoofing_chars(text)
+            .into_iter()
+            .map(|(offset, c)| (offset, format!("U+{:04X}", c as u32)))
+            .collect()
+    }
+    
+    /// Every character normalization changed or removed, as `(byte offset, original, replacement)`
+    /// with an empty replacement for a removed character
+    #[pyo3(name = "normalize_debug")]
+    fn py_normalize_debug(&self, text: &str) -> Vec<(usize, char, String)> {
+        self.normalize_debug(text)
+    }
+    
+    /// Undo leetspeak substitutions and collapse spaced-out letters
+    #[pyo3(name = "deobfuscate")]
+    fn py_deobfuscate(&self, text: &str) -> String {
+        self.deobfuscate(text)
+    }
+}
+
+/// Python iterator from `TextModerator.moderate_batch_iter`, pulling the next chunk of texts from
+/// its source when the previous chunk's results run out
+#[pyclass(name = "BatchIterator")]
+struct PyBatchIterator {
+    moderator: Py<TextModerator>,
+    texts: Py<PyIterator>,
+    chunk_size: usize,
+    ready: VecDeque<ModerationResult>,
+}
+
+#[pymethods]
+impl PyBatchIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+    
+    fn __next__(&mut self, py: Python) -> PyResult<Option<ModerationResult>> {
+        if self.ready.is_empty() {
+            let mut chunk = Vec::with_capacity(self.chunk_size);
+            for text in self.texts.as_ref(py).take(self.chunk_size) {
+                chunk.push(text?.extract::<String>()?);
+            }
+            let texts: Vec<&str> = chunk.iter().map(String::as_str).collect();
+            let moderator = self.moderator.borrow(py);
+            let moderator: &TextModerator = &moderator;
+            // Other Python threads, e.g. consumers of earlier results, run while the chunk is moderated
+            self.ready = py.allow_threads(|| moderator.moderate_batch(&texts)).into();
+        }
+        Ok(self.ready.pop_front())
+    }
+}
+
+/// Python handle on a `StreamingModerator`, keeping its moderator alive between chunks
+#[pyclass(name = "StreamingModerator")]
+struct PyStreamingModerator {
+    moderator: Py<TextModerator>,
+    stream: StreamingModerator,
+}
+
+#[pymethods]
+impl PyStreamingModerator {
+    #[new]
+    fn new(py: Python, moderator: Py<TextModerator>) -> Self {
+        let stream = StreamingModerator::new(&moderator.borrow(py));
+        Self { moderator, stream }
+    }
+    
+    /// Append a chunk, moderating whatever complete words have accumulated
+    fn feed(&mut self, py: Python, chunk: &str) {
+        self.stream.feed(&self.moderator.borrow(py), chunk);
+    }
+    
+    /// Moderate any remaining input and return the combined result, resetting the stream
+    fn finalize(&mut self, py: Python) -> ModerationResult {
+        self.stream.finalize(&self.moderator.borrow(py))
+    }
+}
+
+/// Python handle on an `AuthorTracker`, keeping its moderator alive between messages
+#[pyclass(name = "AuthorTracker")]
+struct PyAuthorTracker {
+    moderator: Py<TextModerator>,
+    tracker: AuthorTracker,
+}
+
+#[pymethods]
+impl PyAuthorTracker {
+    #[new]
+    #[pyo3(signature = (moderator, capacity = 10_000))]
+    fn new(moderator: Py<TextModerator>, capacity: usize) -> Self {
+        Self {
+            moderator,
+            tracker: AuthorTracker::new(capacity),
+        }
+    }
+    
+    /// Moderate a message from `author_id` sent at `timestamp` seconds, flagging `burst` over the rate limit
+    fn moderate(&mut self, py: Python, author_id: &str, timestamp: f64, text: &str) -> ModerationResult {
+        self.tracker.moderate(&self.moderator.borrow(py), author_id, timestamp, text)
+    }
+    
+    /// Number of authors currently remembered
+    fn __len__(&self) -> usize {
+        self.tracker.len()
+    }
+    
+    /// Forget every author
+    fn clear(&mut self) {
+        self.tracker.clear();
+    }
+}
+
+#[pymethods]
+impl TextDeduper {
+    #[new]
+    #[pyo3(signature = (capacity = 10_000, threshold = 0.6))]
+    fn py_new(capacity: usize, threshold: f64) -> Self {
+        Self::new(capacity, threshold)
+    }
+    
+    #[getter]
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+    
+    #[setter]
+    fn set_threshold(&mut self, value: f64) {
+        self.threshold = value;
+    }
+    
+    /// Id of a near-duplicate already seen, or None after remembering the text under the next id
+    #[pyo3(name = "add")]
+    fn py_add(&mut self, text: &str) -> Option<usize> {
+        self.add(text)
+    }
+    
+    /// Number of texts currently remembered
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+    
+    /// Forget every text; ids keep counting up
+    #[pyo3(name = "clear")]
+    fn py_clear(&mut self) {
+        self.clear();
+    }
+}
+
+#[pymethods]
+impl ImageModerator {
+    /// Validation messages are in `locale` where `add_translations` has registered it
+    #[new]
+    #[pyo3(signature = (locale="en"))]
+    fn py_new(locale: &str) -> Self {
+        Self::with_locale(locale)
+    }
+    
+    #[getter]
+    fn get_locale(&self) -> String {
+        self.locale.clone()
+    }
+    
+    #[setter]
+    fn set_locale(&mut self, value: String) {
+        self.locale = value;
+    }
+    
+    /// Register message templates for a locale by key (`"file_too_large"`, `"invalid_image"`, ...);
+    /// `{name}` placeholders such as `{megapixels}` or `{error}` are filled in
+    #[pyo3(name = "add_translations")]
+    fn py_add_translations(&mut self, locale: &str, messages: HashMap<String, String>) {
+        self.add_translations(locale, messages);
+    }
+    
+    #[getter]
+    fn get_skin_ratio_threshold(&self) -> f64 {
+        self.skin_ratio_threshold
+    }
+    
+    #[setter]
+    fn set_skin_ratio_threshold(&mut self, value: f64) {
+        self.skin_ratio_threshold = value;
+    }
+    
+    #[getter]
+    fn get_max_frames(&self) -> usize {
+        self.max_frames
+    }
+    
+    #[setter]
+    fn set_max_frames(&mut self, value: usize) {
+        self.max_frames = value;
+    }
+    
+    #[getter]
+    fn get_max_pixels(&self) -> u64 {
+        self.max_pixels
+    }
+    
+    #[setter]
+    fn set_max_pixels(&mut self, value: u64) {
+        self.max_pixels = value;
+    }
+    
+    #[getter]
+    fn get_min_width(&self) -> u32 {
+        self.min_width
+    }
+    
+    #[setter]
+    fn set_min_width(&mut self, value: u32) {
+        self.min_width = value;
+    }
+    
+    #[getter]
+    fn get_min_height(&self) -> u32 {
+        self.min_height
+    }
+    
+    #[setter]
+    fn set_min_height(&mut self, value: u32) {
+        self.min_height = value;
+    }
+    
+    #[getter]
+    fn get_max_aspect_ratio(&self) -> f64 {
+        self.max_aspect_ratio
+    }
+    
+    #[setter]
+    fn set_max_aspect_ratio(&mut self, value: f64) {
+        self.max_aspect_ratio = value;
+    }
+    
+    #[getter]
+    fn get_min_sharpness(&self) -> f64 {
+        self.min_sharpness
+    }
+    
+    #[setter]
+    fn set_min_sharpness(&mut self, value: f64) {
+        self.min_sharpness = value;
+    }
+    
+    #[getter]
+    fn get_prescreen_frames(&self) -> usize {
+        self.prescreen_frames
+    }
+    
+    #[setter]
+    fn set_prescreen_frames(&mut self, value: usize) {
+        self.prescreen_frames = value;
+    }
+    
+    #[getter]
+    fn get_low_information_threshold(&self) -> f64 {
+        self.low_information_threshold
+    }
+    
+    #[setter]
+    fn set_low_information_threshold(&mut self, value: f64) {
+        self.low_information_threshold = value;
+    }
+    
+    /// Validate image file
+    #[pyo3(name = "validate_image")]
+    fn py_validate_image(&self, py: Python, file_path: &str) -> PyResult<PyObject> {
+        match self.validate_image(file_path) {
+            Ok(result) => validation_to_dict(py, &result),
+            Err(e) => Err(e.into_pyerr("Image validation failed"))
+        }
+    }
+    
+    /// Awaitable `validate_image` that reads and decodes without blocking the event loop
+    #[cfg(feature = "async")]
+    #[pyo3(name = "validate_image_async")]
+    fn py_validate_image_async<'p>(&self, py: Python<'p>, file_path: String) -> PyResult<&'p PyAny> {
+        let moderator = self.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let result = moderator
+                .validate_image_async(&file_path)
+                .await
+                .map_err(|e| e.into_pyerr("Image validation failed"))?;
+            Python::with_gil(|py| validation_to_dict(py, &result))
+        })
+    }
+    
+    /// Validate an image held in memory, e.g. the bytes of an upload
+    #[pyo3(name = "validate_image_bytes")]
+    fn py_validate_image_bytes(&self, py: Python, data: &[u8]) -> PyResult<PyObject> {
+        match self.validate_image_bytes(data) {
+            Ok(result) => validation_to_dict(py, &result),
+            Err(e) => Err(e.into_pyerr("Image validation failed"))
+        }
+    }
+    
+    /// Validate many image files in parallel; failures are reported per file instead of aborting
+    #[pyo3(name = "validate_batch")]
+    fn py_validate_batch(&self, py: Python, paths: Vec<&str>) -> PyResult<Vec<PyObject>> {
+        let results = self.validate_batch(&paths);
+        paths
+            .iter()
+            .zip(results)
+            .map(|(path, result)| {
+                let dict = validation_to_dict(py, &result)?;
+                dict.as_ref(py).set_item("path", path)?;
+                Ok(dict)
+            })
+            .collect()
+    }
+    
+    /// 64-bit difference hash (dHash) for spotting re-uploads of known images
+    #[pyo3(name = "perceptual_hash")]
+    fn py_perceptual_hash(&self, path: &str) -> PyResult<u64> {
+        self.perceptual_hash(path).map_err(|e| e.into_pyerr("Failed to hash image"))
+    }
+    
+    /// Number of differing bits between two perceptual hashes
+    #[staticmethod]
+    #[pyo3(name = "hamming_distance")]
+    fn py_hamming_distance(a: u64, b: u64) -> u32 {
+        Self::hamming_distance(a, b)
+    }
+    
+    /// Fraction of sampled pixels that fall in a YCbCr skin-tone range
+    #[pyo3(name = "skin_tone_ratio")]
+    fn py_skin_tone_ratio(&self, path: &str) -> PyResult<f64> {
+        self.skin_tone_ratio(path).map_err(|e| e.into_pyerr("Failed to analyze image"))
+    }
+    
+    /// Fraction of sampled pixels close to the image's most common colour; near 1.0 for blank or solid fills
+    #[pyo3(name = "dominant_color_ratio")]
+    fn py_dominant_color_ratio(&self, path: &str) -> PyResult<f64> {
+        self.dominant_color_ratio(path).map_err(|e| e.into_pyerr("Failed to analyze image"))
+    }
+    
+    /// Sharpness (variance of the Laplacian), brightness and contrast (mean and spread of luma, 0-1)
+    #[pyo3(name = "quality_metrics")]
+    fn py_quality_metrics(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        let (sharpness, brightness, contrast) =
+            self.quality_metrics(path).map_err(|e| e.into_pyerr("Failed to analyze image"))?;
+        
+        let dict = PyDict::new(py);
+        dict.set_item("sharpness", sharpness)?;
+        dict.set_item("brightness", brightness)?;
+        dict.set_item("contrast", contrast)?;
+        Ok(dict.into())
+    }
+    
+    /// Cheap NSFW heuristic for routing images to a heavier model; not a classifier on its own
+    #[pyo3(name = "nsfw_prescreen")]
+    fn py_nsfw_prescreen(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        let (is_suspicious, skin_ratio, flat_ratio) =
+            self.nsfw_prescreen(path).map_err(|e| e.into_pyerr("Failed to analyze image"))?;
+        
+        let dict = PyDict::new(py);
+        dict.set_item("is_suspicious", is_suspicious)?;
+        dict.set_item("skin_ratio", skin_ratio)?;
+        dict.set_item("flat_color_ratio", flat_ratio)?;
+        Ok(dict.into())
+    }
+    
+    /// EXIF fields (orientation, GPS, timestamps, device) keyed by tag name; empty when there is no EXIF
+    #[pyo3(name = "read_exif")]
+    fn py_read_exif(&self, path: &str) -> PyResult<HashMap<String, String>> {
+        self.read_exif(path).map_err(|e| e.into_pyerr("Failed to read image"))
+    }
+    
+    /// Write a copy of `input` to `output` with EXIF and other embedded metadata removed
+    #[pyo3(name = "strip_exif")]
+    fn py_strip_exif(&self, input: &str, output: &str) -> PyResult<()> {
+        self.strip_exif(input, output).map_err(|e| e.into_pyerr("Failed to strip metadata"))
+    }
+    
+    /// Text found in the image by OCR, empty when there is none; needs the `ocr` feature
+    #[pyo3(name = "extract_text")]
+    fn py_extract_text(&self, path: &str) -> PyResult<String> {
+        self.extract_text(path).map_err(|e| e.into_pyerr("Failed to extract text"))
+    }
+    
+    /// Moderate the text found in an image, e.g. slurs or threats rendered into a meme
+    #[pyo3(name = "moderate_image_text")]
+    fn py_moderate_image_text(&self, path: &str, moderator: PyRef<TextModerator>) -> PyResult<ModerationResult> {
+        self.moderate_image_text(path, &moderator)
+            .map_err(|e| e.into_pyerr("Failed to extract text"))
+    }
+    
+    /// Moderate a caption and optional image together as `{"is_appropriate", "flagged_categories",
+    /// "confidence_score", "flags", "text", "image"}`, each flag a `(category, "text" | "image")` pair
+    #[pyo3(name = "moderate_post", signature = (moderator, text, image_path=None))]
+    fn py_moderate_post(&self, py: Python, moderator: PyRef<TextModerator>, text: &str, image_path: Option<&str>) -> PyResult<PyObject> {
+        let post = self
+            .moderate_post(&moderator, text, image_path)
+            .map_err(|e| e.into_pyerr("Failed to moderate post"))?;
+        let dict = PyDict::new(py);
+        dict.set_item("is_appropriate", post.is_appropriate)?;
+        dict.set_item("flagged_categories", post.flagged_categories)?;
+        dict.set_item("confidence_score", post.confidence_score)?;
+        dict.set_item("flags", post.flags)?;
+        dict.set_item("text", post.text.into_py(py))?;
+        let image = post.image.map(|validation| validation_to_dict(py, &validation)).transpose()?;
+        dict.set_item("image", image)?;
+        Ok(dict.into())
+    }
+    
+    /// Moderate an image file on its own, as an `ImageModerationResult`
+    #[pyo3(name = "moderate_image")]
+    fn py_moderate_image(&self, path: &str) -> PyResult<ImageModerationResult> {
+        self.moderate_image(path).map_err(|e| e.into_pyerr("Failed to moderate image"))
+    }
+    
+    /// Get image metadata
+    #[pyo3(name = "get_image_info")]
+    fn py_get_image_info(&self, py: Python, file_path: &str) -> PyResult<PyObject> {
+        match self.get_image_info(file_path) {
+            Ok((info, frames)) => info_to_dict(py, info, frames),
+            Err(e) => Err(e.into_pyerr("Failed to get image info"))
+        }
+    }
+    
+    /// Awaitable `get_image_info` that reads and decodes without blocking the event loop
+    #[cfg(feature = "async")]
+    #[pyo3(name = "get_image_info_async")]
+    fn py_get_image_info_async<'p>(&self, py: Python<'p>, file_path: String) -> PyResult<&'p PyAny> {
+        let moderator = self.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let (info, frames) = moderator
+                .get_image_info_async(&file_path)
+                .await
+                .map_err(|e| e.into_pyerr("Failed to get image info"))?;
+            Python::with_gil(|py| info_to_dict(py, info, frames))
+        })
+    }
+    
+    /// Get metadata for an image held in memory
+    #[pyo3(name = "get_image_info_bytes")]
+    fn py_get_image_info_bytes(&self, py: Python, data: &[u8]) -> PyResult<PyObject> {
+        match self.get_image_info_bytes(data) {
+            Ok((info, frames)) => info_to_dict(py, info, frames),
+            Err(e) => Err(e.into_pyerr("Failed to get image info"))
+        }
+    }
+}
+
+fn validation_to_dict(py: Python, validation: &ImageValidation) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("is_valid", validation.is_valid)?;
+    dict.set_item("message", &validation.message)?;
+    dict.set_item("file_info", validation.file_info.clone())?;
+    dict.set_item("flagged_categories", &validation.flagged_categories)?;
+    dict.set_item("checks", validation.checks.clone())?;
+    Ok(dict.into())
+}
+
+fn info_to_dict(py: Python, info: ImageInfo, frame_count: usize) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("width", info.0)?;
+    dict.set_item("height", info.1)?;
+    dict.set_item("format", info.2)?;
+    dict.set_item("file_size", info.3)?;
+    dict.set_item("frame_count", frame_count)?;
+    Ok(dict.into())
+}
+
+/// Bad patterns and wordlists raise ValueError, as they did before `ModerationError` covered them
+fn value_error(e: ModerationError) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())
+}
+
+/// Wordlist files that cannot be read raise IOError; malformed ones raise ValueError
+fn wordlist_error(path: &str, e: ModerationError) -> PyErr {
+    match e {
+        ModerationError::NotFound(_) | ModerationError::Io(_) => {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to read wordlist {}: {}", path, e))
+        }
+        e => value_error(e),
+    }
+}
+
+/// Snapshots that cannot be read or written raise IOError; malformed ones raise ValueError
+fn snapshot_error(path: &str, e: ModerationError) -> PyErr {
+    match e {
+        ModerationError::NotFound(_) | ModerationError::Io(_) => {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to access snapshot {}: {}", path, e))
+        }
+        e => value_error(e),
+    }
+}
+
+impl ModerationError {
+    /// Raise as the matching Python exception, prefixing the message with `context`
+    fn into_pyerr(self, context: &str) -> PyErr {
+        let message = format!("{}: {}", context, self);
+        match self {
+            ModerationError::NotFound(_) => exceptions::ImageNotFoundError::new_err(message),
+            ModerationError::Io(_) => exceptions::ImageIOError::new_err(message),
+            ModerationError::UnsupportedFormat(_) => exceptions::UnsupportedFormatError::new_err(message),
+            ModerationError::CorruptImage(_) => exceptions::CorruptImageError::new_err(message),
+            ModerationError::Ocr(_) => exceptions::OcrError::new_err(message),
+            ModerationError::InvalidPattern(_)
+            | ModerationError::InvalidWordlist(_)
+            | ModerationError::InvalidSnapshot(_)
+            | ModerationError::InvalidDocument(_) => {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(message)
+            }
+        }
+    }
+}
+
+/// Python exceptions for `ModerationError`; the base derives from RuntimeError so existing handlers still catch them
+mod exceptions {
+    pyo3::create_exception!(rust_moderation, ModerationError, pyo3::exceptions::PyRuntimeError, "Base class for image moderation failures.");
+    pyo3::create_exception!(rust_moderation, ImageNotFoundError, ModerationError, "The image file does not exist.");
+    pyo3::create_exception!(rust_moderation, ImageIOError, ModerationError, "The image could not be read or written.");
+    pyo3::create_exception!(rust_moderation, UnsupportedFormatError, ModerationError, "The image format is not supported.");
+    pyo3::create_exception!(rust_moderation, CorruptImageError, ModerationError, "The image data is damaged.");
+    pyo3::create_exception!(rust_moderation, OcrError, ModerationError, "Text extraction failed or OCR is not available.");
+}
+
+/// Python module definition
+#[pymodule]
+fn rust_moderation(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<ModerationResult>()?;
+    m.add_class::<ModerationConfig>()?;
+    m.add_class::<Severity>()?;
+    m.add_class::<MatchMode>()?;
+    m.add_class::<LengthPolicy>()?;
+    m.add_class::<TextModerator>()?;
+    m.add_class::<PyStreamingModerator>()?;
+    m.add_class::<PyBatchIterator>()?;
+    m.add_class::<PyAuthorTracker>()?;
+    m.add_class::<TextDeduper>()?;
+    m.add_class::<ImageModerator>()?;
+    m.add_class::<ImageModerationResult>()?;
+    m.add("ModerationError", py.get_type::<exceptions::ModerationError>())?;
+    m.add("ImageNotFoundError", py.get_type::<exceptions::ImageNotFoundError>())?;
+    m.add("ImageIOError", py.get_type::<exceptions::ImageIOError>())?;
+    m.add("UnsupportedFormatError", py.get_type::<exceptions::UnsupportedFormatError>())?;
+    m.add("CorruptImageError", py.get_type::<exceptions::CorruptImageError>())?;
+    m.add("OcrError", py.get_type::<exceptions::OcrError>())?;
+    Ok(())
+}